@@ -1,5 +1,6 @@
 mod volume_controller;
 
+use std::thread;
 use tauri::Emitter;
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
 
@@ -21,11 +22,84 @@ fn get_volume() -> Result<f32, String> {
     volume_controller::get_volume()
 }
 
+/// `volume_up`/`volume_down` の刻み幅とスケールを設定する
+#[tauri::command]
+fn set_step_config(step: f32, scale: volume_controller::VolumeScale) -> Result<(), String> {
+    volume_controller::set_step_config(step, scale)
+}
+
+/// 現在のミュート状態を取得
+#[tauri::command]
+fn get_mute() -> Result<bool, String> {
+    volume_controller::get_mute()
+}
+
+/// ミュート状態を設定
+#[tauri::command]
+fn set_mute(muted: bool) -> Result<bool, String> {
+    volume_controller::set_mute(muted)
+}
+
+/// ミュート状態を反転する
+#[tauri::command]
+fn toggle_mute() -> Result<bool, String> {
+    volume_controller::toggle_mute()
+}
+
+/// 起動中アプリケーションのオーディオセッション一覧を取得する
+#[tauri::command]
+fn list_sessions() -> Result<Vec<volume_controller::SessionInfo>, String> {
+    volume_controller::list_sessions()
+}
+
+/// 指定したアプリケーション（プロセスID）の音量を設定する
+#[tauri::command]
+fn set_session_volume(pid: u32, level: f32) -> Result<f32, String> {
+    volume_controller::set_session_volume(pid, level)
+}
+
+/// 有効な出力デバイスの一覧を取得する
+#[tauri::command]
+fn list_devices() -> Result<Vec<volume_controller::DeviceInfo>, String> {
+    volume_controller::list_devices()
+}
+
+/// 操作対象の出力デバイスを切り替える（Windowsの既定デバイスは変更しない）
+#[tauri::command]
+fn set_device(device_id: String) -> Result<(), String> {
+    volume_controller::set_device(device_id)
+}
+
+/// エンドポイントのチャンネル数を取得する
+#[tauri::command]
+fn get_channel_count() -> Result<u32, String> {
+    volume_controller::get_channel_count()
+}
+
+/// 全チャンネルの現在の音量を取得する
+#[tauri::command]
+fn get_channel_volumes() -> Result<Vec<f32>, String> {
+    volume_controller::get_channel_volumes()
+}
+
+/// 指定したチャンネルの音量を設定する
+#[tauri::command]
+fn set_channel_volume(index: u32, level: f32) -> Result<f32, String> {
+    volume_controller::set_channel_volume(index, level)
+}
+
+/// L/Rバランスを設定する (-1.0: 左のみ 〜 0.0: 中央 〜 +1.0: 右のみ)
+#[tauri::command]
+fn set_balance(balance: f32) -> Result<(), String> {
+    volume_controller::set_balance(balance)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // ショートカットを定義
     let shortcut_up = Shortcut::new(Some(Modifiers::ALT), Code::ArrowUp);
     let shortcut_down = Shortcut::new(Some(Modifiers::ALT), Code::ArrowDown);
+    let shortcut_mute = Shortcut::new(Some(Modifiers::ALT), Code::KeyM);
 
     let mut builder = tauri::Builder::default();
 
@@ -43,20 +117,42 @@ pub fn run() {
                     if event.state() == ShortcutState::Pressed {
                         if shortcut == &shortcut_up {
                             // Alt+↑: ボリュームアップ
-                            let _ = volume_controller::volume_up();
-                            let _ = app.emit("volume-changed", ());
+                            if let Ok(level) = volume_controller::volume_up() {
+                                let _ = app.emit("volume-changed", level);
+                            }
                         } else if shortcut == &shortcut_down {
                             // Alt+↓: ボリュームダウン
-                            let _ = volume_controller::volume_down();
-                            let _ = app.emit("volume-changed", ());
+                            if let Ok(level) = volume_controller::volume_down() {
+                                let _ = app.emit("volume-changed", level);
+                            }
+                        } else if shortcut == &shortcut_mute {
+                            // Alt+M: ミュート切り替え
+                            if let Ok(muted) = volume_controller::toggle_mute() {
+                                let _ = app.emit("mute-changed", muted);
+                            }
                         }
                     }
                 })
                 .build(),
         )
         .setup(move |app| {
-            // ボリュームコントローラーを初期化
-            volume_controller::init_volume_controller();
+            // ボリュームコントローラーを初期化し、OS側で起きた変更の通知を購読する
+            if let Some((volume_changed_rx, device_changed_rx)) =
+                volume_controller::init_volume_controller()
+            {
+                let app_handle = app.handle().clone();
+                thread::spawn(move || {
+                    while let Ok(level) = volume_changed_rx.recv() {
+                        let _ = app_handle.emit("volume-changed", level);
+                    }
+                });
+                let app_handle = app.handle().clone();
+                thread::spawn(move || {
+                    while let Ok(device_name) = device_changed_rx.recv() {
+                        let _ = app_handle.emit("device-changed", device_name);
+                    }
+                });
+            }
 
             // グローバルショートカットを登録（失敗しても続行）
             if let Err(e) = app.global_shortcut().register(shortcut_up) {
@@ -65,9 +161,28 @@ pub fn run() {
             if let Err(e) = app.global_shortcut().register(shortcut_down) {
                 eprintln!("Failed to register shortcut_down: {:?}", e);
             }
+            if let Err(e) = app.global_shortcut().register(shortcut_mute) {
+                eprintln!("Failed to register shortcut_mute: {:?}", e);
+            }
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![volume_up, volume_down, get_volume])
+        .invoke_handler(tauri::generate_handler![
+            volume_up,
+            volume_down,
+            get_volume,
+            set_step_config,
+            get_mute,
+            set_mute,
+            toggle_mute,
+            list_sessions,
+            set_session_volume,
+            list_devices,
+            set_device,
+            get_channel_count,
+            get_channel_volumes,
+            set_channel_volume,
+            set_balance
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
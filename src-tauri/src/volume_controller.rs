@@ -10,21 +10,98 @@ use std::thread;
 
 #[cfg(windows)]
 use windows::{
+    core::{implement, PCWSTR, GUID},
+    Win32::Devices::Properties::PROPERTYKEY,
+    Win32::Foundation::CloseHandle,
     Win32::Media::Audio::{
-        eConsole, eRender, Endpoints::IAudioEndpointVolume, IMMDeviceEnumerator, MMDeviceEnumerator,
+        eConsole, eRender,
+        Endpoints::{
+            IAudioEndpointVolume, IAudioEndpointVolumeCallback, IAudioEndpointVolumeCallback_Impl,
+            AUDIO_VOLUME_NOTIFICATION_DATA,
+        },
+        EDataFlow, ERole, IAudioSessionControl2, IAudioSessionManager2, IMMDevice,
+        IMMDeviceEnumerator, IMMNotificationClient, IMMNotificationClient_Impl,
+        ISimpleAudioVolume, MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
     },
     Win32::System::Com::{
-        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER,
-        COINIT_MULTITHREADED,
+        CoCreateInstance, CoInitializeEx, CoTaskMemFree, CoUninitialize,
+        StructuredStorage::PropVariantToStringAlloc, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+        STGM_READ,
     },
+    Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    },
+    Win32::UI::Shell::PropertiesSystem::PKEY_Device_FriendlyName,
 };
 
+use serde::{Deserialize, Serialize};
+
+/// 個々のアプリケーション（オーディオセッション）の情報
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionInfo {
+    pub pid: u32,
+    pub display_name: String,
+    pub icon_path: String,
+    pub volume: f32,
+    pub muted: bool,
+}
+
+/// 出力デバイスの情報
+#[derive(Debug, Clone, Serialize)]
+pub struct DeviceInfo {
+    pub id: String,
+    pub name: String,
+    /// コントローラーが現在操作しているデバイスなら true
+    /// （`set_device` で別のデバイスを選んだ後はWindowsの既定デバイスと一致しない）
+    pub is_default: bool,
+}
+
+/// ボリュームの刻み方
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum VolumeScale {
+    /// スカラー値 (0.0 - 1.0) に対して一定量を加減する
+    Linear,
+    /// デシベル値に対して一定量を加減し、知覚的な音量変化を均一にする
+    Decibel,
+}
+
+/// `VolumeUp`/`VolumeDown` の刻み幅設定
+#[derive(Debug, Clone, Copy)]
+struct StepConfig {
+    step: f32,
+    scale: VolumeScale,
+}
+
+impl Default for StepConfig {
+    fn default() -> Self {
+        Self {
+            step: 0.05,
+            scale: VolumeScale::Linear,
+        }
+    }
+}
+
 /// ボリューム操作コマンド
 enum VolumeCommand {
     GetVolume(Sender<Result<f32, String>>),
     SetVolume(f32, Sender<Result<f32, String>>),
     VolumeUp(Sender<Result<f32, String>>),
     VolumeDown(Sender<Result<f32, String>>),
+    SetStepConfig(f32, VolumeScale, Sender<Result<(), String>>),
+    GetChannelCount(Sender<Result<u32, String>>),
+    GetChannelVolumes(Sender<Result<Vec<f32>, String>>),
+    SetChannelVolume(u32, f32, Sender<Result<f32, String>>),
+    SetBalance(f32, Sender<Result<(), String>>),
+    GetMute(Sender<Result<bool, String>>),
+    SetMute(bool, Sender<Result<bool, String>>),
+    ToggleMute(Sender<Result<bool, String>>),
+    ListSessions(Sender<Result<Vec<SessionInfo>, String>>),
+    SetSessionVolume(u32, f32, Sender<Result<f32, String>>),
+    ListDevices(Sender<Result<Vec<DeviceInfo>, String>>),
+    SetDevice(String, Sender<Result<(), String>>),
+    /// 既定デバイスの切り替え・抜き差しを検知した際に内部的に送出される
+    RebuildEndpoint,
     Shutdown,
 }
 
@@ -32,25 +109,130 @@ enum VolumeCommand {
 static VOLUME_CONTROLLER: Lazy<Mutex<Option<Sender<VolumeCommand>>>> =
     Lazy::new(|| Mutex::new(None));
 
+/// 自分自身が発行した変更通知を見分けるためのプロセス固有コンテキストGUID
+///
+/// `SetMasterVolumeLevelScalar` 等の `pguidEventContext` 引数に渡し、
+/// `IAudioEndpointVolumeCallback::OnNotify` 側で突き合わせることで
+/// 自己エコーを除外する。
+#[cfg(windows)]
+static EVENT_CONTEXT: Lazy<GUID> = Lazy::new(|| GUID::new().unwrap_or_default());
+
+/// `IAudioEndpointVolumeCallback` の実装
+///
+/// Windowsのトレイやメディアキー、他アプリからの音量変更も含め、
+/// マスターボリュームに変化があるたびに `OnNotify` が呼ばれる。
+/// 新しいレベルを `sender` でワーカースレッドの外へ通知する。
+#[cfg(windows)]
+#[implement(IAudioEndpointVolumeCallback)]
+struct VolumeChangeCallback {
+    sender: Sender<f32>,
+}
+
+#[cfg(windows)]
+impl IAudioEndpointVolumeCallback_Impl for VolumeChangeCallback {
+    fn OnNotify(&self, data: *mut AUDIO_VOLUME_NOTIFICATION_DATA) -> windows::core::Result<()> {
+        if data.is_null() {
+            return Ok(());
+        }
+
+        let data = unsafe { &*data };
+
+        // 自分自身が発行した変更はフロントエンドに二重通知しない
+        if data.guidEventContext == *EVENT_CONTEXT {
+            return Ok(());
+        }
+
+        let _ = self.sender.send(data.fMasterVolume);
+        Ok(())
+    }
+}
+
+/// `IMMNotificationClient` の実装
+///
+/// 既定の出力デバイスが切り替わったり、デバイスが抜き差しされたりした際に
+/// Windowsから呼び出される。ワーカースレッドに再構築を依頼するため、
+/// コマンドチャネルへ `RebuildEndpoint` を送るだけに留める（実際の再構築は
+/// ワーカースレッド側で行い、COMオブジェクトをスレッドをまたいで扱わない）。
+#[cfg(windows)]
+#[implement(IMMNotificationClient)]
+struct DeviceNotificationClient {
+    command_tx: Sender<VolumeCommand>,
+}
+
+#[cfg(windows)]
+impl IMMNotificationClient_Impl for DeviceNotificationClient {
+    fn OnDeviceStateChanged(&self, _device_id: &PCWSTR, _new_state: u32) -> windows::core::Result<()> {
+        let _ = self.command_tx.send(VolumeCommand::RebuildEndpoint);
+        Ok(())
+    }
+
+    fn OnDeviceAdded(&self, _device_id: &PCWSTR) -> windows::core::Result<()> {
+        Ok(())
+    }
+
+    fn OnDeviceRemoved(&self, _device_id: &PCWSTR) -> windows::core::Result<()> {
+        Ok(())
+    }
+
+    fn OnDefaultDeviceChanged(
+        &self,
+        flow: EDataFlow,
+        role: ERole,
+        _default_device_id: &PCWSTR,
+    ) -> windows::core::Result<()> {
+        // 既定の「出力・コンソール」デバイスの変更だけを追う
+        if flow == eRender && role == eConsole {
+            let _ = self.command_tx.send(VolumeCommand::RebuildEndpoint);
+        }
+        Ok(())
+    }
+
+    fn OnPropertyValueChanged(
+        &self,
+        _device_id: &PCWSTR,
+        _key: &PROPERTYKEY,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+}
+
 /// ボリュームコントローラーを初期化
-pub fn init_volume_controller() {
+///
+/// 戻り値のチャネルから、他アプリやOS側で行われた音量変更・既定デバイスの
+/// 変更（フロントエンドに転送すべきもの）を受信できる。
+///
+/// ワーカースレッドは1つしか存在せず、通知チャネルの送信側もそのスレッドに
+/// 1組しか渡せないため、2回目以降の呼び出しでは（送信側を持たない、
+/// 受信してもすぐ `Err` になる）チャネルを返すのではなく `None` を返す。
+pub fn init_volume_controller() -> Option<(Receiver<f32>, Receiver<String>)> {
     let mut controller = VOLUME_CONTROLLER.lock().unwrap();
     if controller.is_some() {
-        return; // 既に初期化済み
+        return None; // 既に初期化済み
     }
 
+    let (notify_tx, notify_rx) = mpsc::channel::<f32>();
+    let (device_notify_tx, device_notify_rx) = mpsc::channel::<String>();
+
     let (tx, rx) = mpsc::channel::<VolumeCommand>();
+    let command_tx = tx.clone();
     *controller = Some(tx);
 
     // 別スレッドでCOMを初期化してボリューム操作を行う
     thread::spawn(move || {
-        volume_worker_thread(rx);
+        volume_worker_thread(command_tx, rx, notify_tx, device_notify_tx);
     });
+
+    Some((notify_rx, device_notify_rx))
 }
 
 /// ボリュームワーカースレッド
 #[cfg(windows)]
-fn volume_worker_thread(rx: Receiver<VolumeCommand>) {
+fn volume_worker_thread(
+    command_tx: Sender<VolumeCommand>,
+    rx: Receiver<VolumeCommand>,
+    notify_tx: Sender<f32>,
+    device_notify_tx: Sender<String>,
+) {
     unsafe {
         // MTAとしてCOMを初期化
         // CoInitializeExはHRESULTを返す。ok()でResult<(), Error>に変換
@@ -59,18 +241,65 @@ fn volume_worker_thread(rx: Receiver<VolumeCommand>) {
             return;
         }
 
+        // デバイス列挙子を作成
+        let enumerator: IMMDeviceEnumerator =
+            match CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_INPROC_SERVER) {
+                Ok(e) => e,
+                Err(e) => {
+                    eprintln!("[VolumeController] Failed to create device enumerator: {:?}", e);
+                    CoUninitialize();
+                    return;
+                }
+            };
+
+        // 既定デバイスの切り替え・抜き差しを監視する
+        let device_notify_client: IMMNotificationClient = DeviceNotificationClient {
+            command_tx: command_tx.clone(),
+        }
+        .into();
+        if let Err(e) = enumerator.RegisterEndpointNotificationCallback(&device_notify_client) {
+            eprintln!(
+                "[VolumeController] Failed to register endpoint notifications: {:?}",
+                e
+            );
+        }
+
+        // デフォルトの出力デバイスを取得
+        let mut device = match enumerator.GetDefaultAudioEndpoint(eRender, eConsole) {
+            Ok(dev) => dev,
+            Err(e) => {
+                eprintln!("[VolumeController] Failed to get default device: {:?}", e);
+                let _ = enumerator.UnregisterEndpointNotificationCallback(&device_notify_client);
+                CoUninitialize();
+                return;
+            }
+        };
+
         // オーディオエンドポイントを取得
-        let endpoint = match get_audio_endpoint() {
+        let mut endpoint = match get_audio_endpoint(&device) {
             Ok(ep) => ep,
             Err(e) => {
                 eprintln!("[VolumeController] Failed to get audio endpoint: {}", e);
+                let _ = enumerator.UnregisterEndpointNotificationCallback(&device_notify_client);
                 CoUninitialize();
                 return;
             }
         };
 
+        // 変更通知コールバックを登録
+        let mut callback: IAudioEndpointVolumeCallback = VolumeChangeCallback {
+            sender: notify_tx.clone(),
+        }
+        .into();
+        if let Err(e) = endpoint.RegisterControlChangeNotify(&callback) {
+            eprintln!("[VolumeController] Failed to register change notify: {:?}", e);
+        }
+
         println!("[VolumeController] Volume controller initialized successfully");
 
+        // VolumeUp/VolumeDownの刻み幅設定
+        let mut step_config = StepConfig::default();
+
         // メッセージループ
         while let Ok(cmd) = rx.recv() {
             match cmd {
@@ -83,37 +312,144 @@ fn volume_worker_thread(rx: Receiver<VolumeCommand>) {
                 VolumeCommand::SetVolume(level, response_tx) => {
                     let clamped = level.clamp(0.0, 1.0);
                     let result = endpoint
-                        .SetMasterVolumeLevelScalar(clamped, std::ptr::null())
+                        .SetMasterVolumeLevelScalar(clamped, &*EVENT_CONTEXT as *const GUID)
                         .map(|_| clamped)
                         .map_err(|e| format!("Failed to set volume: {:?}", e));
                     let _ = response_tx.send(result);
                 }
                 VolumeCommand::VolumeUp(response_tx) => {
-                    let result = match endpoint.GetMasterVolumeLevelScalar() {
-                        Ok(current) => {
-                            let new_level = (current + 0.05).min(1.0);
-                            match endpoint.SetMasterVolumeLevelScalar(new_level, std::ptr::null()) {
-                                Ok(_) => Ok(new_level),
-                                Err(e) => Err(format!("Failed to set volume: {:?}", e)),
-                            }
-                        }
-                        Err(e) => Err(format!("Failed to get volume: {:?}", e)),
-                    };
+                    let result = step_volume(&endpoint, &step_config, 1.0);
                     let _ = response_tx.send(result);
                 }
                 VolumeCommand::VolumeDown(response_tx) => {
-                    let result = match endpoint.GetMasterVolumeLevelScalar() {
+                    let result = step_volume(&endpoint, &step_config, -1.0);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetStepConfig(step, scale, response_tx) => {
+                    step_config = StepConfig { step, scale };
+                    let _ = response_tx.send(Ok(()));
+                }
+                VolumeCommand::GetChannelCount(response_tx) => {
+                    let result = endpoint
+                        .GetChannelCount()
+                        .map_err(|e| format!("Failed to get channel count: {:?}", e));
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::GetChannelVolumes(response_tx) => {
+                    let result = get_channel_volumes(&endpoint);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetChannelVolume(index, level, response_tx) => {
+                    let clamped = level.clamp(0.0, 1.0);
+                    let result = endpoint
+                        .SetChannelVolumeLevelScalar(index, clamped, &*EVENT_CONTEXT as *const GUID)
+                        .map(|_| clamped)
+                        .map_err(|e| format!("Failed to set channel {} volume: {:?}", index, e));
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetBalance(balance, response_tx) => {
+                    let result = set_balance(&endpoint, balance);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::GetMute(response_tx) => {
+                    let result = endpoint
+                        .GetMute()
+                        .map(|m| m.as_bool())
+                        .map_err(|e| format!("Failed to get mute: {:?}", e));
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetMute(muted, response_tx) => {
+                    let result = endpoint
+                        .SetMute(muted, &*EVENT_CONTEXT as *const GUID)
+                        .map(|_| muted)
+                        .map_err(|e| format!("Failed to set mute: {:?}", e));
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::ToggleMute(response_tx) => {
+                    let result = match endpoint.GetMute() {
                         Ok(current) => {
-                            let new_level = (current - 0.05).max(0.0);
-                            match endpoint.SetMasterVolumeLevelScalar(new_level, std::ptr::null()) {
-                                Ok(_) => Ok(new_level),
-                                Err(e) => Err(format!("Failed to set volume: {:?}", e)),
+                            let new_muted = !current.as_bool();
+                            match endpoint.SetMute(new_muted, &*EVENT_CONTEXT as *const GUID) {
+                                Ok(_) => Ok(new_muted),
+                                Err(e) => Err(format!("Failed to set mute: {:?}", e)),
                             }
                         }
-                        Err(e) => Err(format!("Failed to get volume: {:?}", e)),
+                        Err(e) => Err(format!("Failed to get mute: {:?}", e)),
                     };
                     let _ = response_tx.send(result);
                 }
+                VolumeCommand::ListSessions(response_tx) => {
+                    let result = list_audio_sessions(&device);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetSessionVolume(pid, level, response_tx) => {
+                    let result = set_session_volume(&device, pid, level);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::ListDevices(response_tx) => {
+                    let result = list_output_devices(&enumerator, &device);
+                    let _ = response_tx.send(result);
+                }
+                VolumeCommand::SetDevice(device_id, response_tx) => {
+                    let wide_id: Vec<u16> = device_id.encode_utf16().chain(std::iter::once(0)).collect();
+                    match enumerator
+                        .GetDevice(PCWSTR(wide_id.as_ptr()))
+                        .map_err(|e| format!("Failed to get device {}: {:?}", device_id, e))
+                        .and_then(|new_device| {
+                            get_audio_endpoint(&new_device).map(|new_endpoint| (new_device, new_endpoint))
+                        }) {
+                        Ok((new_device, new_endpoint)) => {
+                            let _ = endpoint.UnregisterControlChangeNotify(&callback);
+                            device = new_device;
+                            endpoint = new_endpoint;
+                            callback = VolumeChangeCallback {
+                                sender: notify_tx.clone(),
+                            }
+                            .into();
+                            if let Err(e) = endpoint.RegisterControlChangeNotify(&callback) {
+                                eprintln!(
+                                    "[VolumeController] Failed to re-register change notify: {:?}",
+                                    e
+                                );
+                            }
+                            let _ = device_notify_tx.send(device_friendly_name(&device).unwrap_or(device_id));
+                            let _ = response_tx.send(Ok(()));
+                        }
+                        Err(e) => {
+                            let _ = response_tx.send(Err(e));
+                        }
+                    }
+                }
+                VolumeCommand::RebuildEndpoint => {
+                    let rebuilt = enumerator
+                        .GetDefaultAudioEndpoint(eRender, eConsole)
+                        .map_err(|e| format!("Failed to get default audio endpoint: {:?}", e))
+                        .and_then(|new_device| {
+                            get_audio_endpoint(&new_device).map(|new_endpoint| (new_device, new_endpoint))
+                        });
+                    match rebuilt {
+                        Ok((new_device, new_endpoint)) => {
+                            let _ = endpoint.UnregisterControlChangeNotify(&callback);
+                            device = new_device;
+                            endpoint = new_endpoint;
+                            callback = VolumeChangeCallback {
+                                sender: notify_tx.clone(),
+                            }
+                            .into();
+                            if let Err(e) = endpoint.RegisterControlChangeNotify(&callback) {
+                                eprintln!(
+                                    "[VolumeController] Failed to re-register change notify: {:?}",
+                                    e
+                                );
+                            }
+                            let name = device_friendly_name(&device).unwrap_or_default();
+                            let _ = device_notify_tx.send(name);
+                        }
+                        Err(e) => {
+                            eprintln!("[VolumeController] Failed to rebuild endpoint: {}", e);
+                        }
+                    }
+                }
                 VolumeCommand::Shutdown => {
                     println!("[VolumeController] Shutting down");
                     break;
@@ -121,41 +457,328 @@ fn volume_worker_thread(rx: Receiver<VolumeCommand>) {
             }
         }
 
+        let _ = endpoint.UnregisterControlChangeNotify(&callback);
+        let _ = enumerator.UnregisterEndpointNotificationCallback(&device_notify_client);
         CoUninitialize();
     }
 }
 
 /// オーディオエンドポイントを取得
 #[cfg(windows)]
-unsafe fn get_audio_endpoint() -> Result<IAudioEndpointVolume, String> {
-    // デバイス列挙子を作成
-    let enumerator: IMMDeviceEnumerator =
-        CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_INPROC_SERVER)
-            .map_err(|e| format!("Failed to create device enumerator: {:?}", e))?;
+unsafe fn get_audio_endpoint(device: &IMMDevice) -> Result<IAudioEndpointVolume, String> {
+    // IAudioEndpointVolumeを取得
+    device
+        .Activate(CLSCTX_INPROC_SERVER, None)
+        .map_err(|e| format!("Failed to activate audio endpoint volume: {:?}", e))
+}
 
-    // デフォルト出力デバイスを取得
-    let device = enumerator
-        .GetDefaultAudioEndpoint(eRender, eConsole)
-        .map_err(|e| format!("Failed to get default audio endpoint: {:?}", e))?;
+/// 設定された刻み幅・スケールに従ってマスターボリュームを一段階変化させる
+///
+/// `direction` は増加方向なら `1.0`、減少方向なら `-1.0` を渡す。
+#[cfg(windows)]
+unsafe fn step_volume(
+    endpoint: &IAudioEndpointVolume,
+    config: &StepConfig,
+    direction: f32,
+) -> Result<f32, String> {
+    match config.scale {
+        VolumeScale::Linear => {
+            let current = endpoint
+                .GetMasterVolumeLevelScalar()
+                .map_err(|e| format!("Failed to get volume: {:?}", e))?;
+            let new_level = (current + direction * config.step).clamp(0.0, 1.0);
+            endpoint
+                .SetMasterVolumeLevelScalar(new_level, &*EVENT_CONTEXT as *const GUID)
+                .map(|_| new_level)
+                .map_err(|e| format!("Failed to set volume: {:?}", e))
+        }
+        VolumeScale::Decibel => {
+            let current_db = endpoint
+                .GetMasterVolumeLevel()
+                .map_err(|e| format!("Failed to get volume: {:?}", e))?;
 
-    // IAudioEndpointVolumeを取得
-    let endpoint: IAudioEndpointVolume = device
+            let mut min_db = 0f32;
+            let mut max_db = 0f32;
+            let mut increment_db = 0f32;
+            endpoint
+                .GetVolumeRange(&mut min_db, &mut max_db, &mut increment_db)
+                .map_err(|e| format!("Failed to get volume range: {:?}", e))?;
+
+            let new_db = (current_db + direction * config.step).clamp(min_db, max_db);
+            endpoint
+                .SetMasterVolumeLevel(new_db, &*EVENT_CONTEXT as *const GUID)
+                .map_err(|e| format!("Failed to set volume: {:?}", e))?;
+
+            endpoint
+                .GetMasterVolumeLevelScalar()
+                .map_err(|e| format!("Failed to get volume: {:?}", e))
+        }
+    }
+}
+
+/// 全チャンネルの現在の音量を取得する
+#[cfg(windows)]
+unsafe fn get_channel_volumes(endpoint: &IAudioEndpointVolume) -> Result<Vec<f32>, String> {
+    let count = endpoint
+        .GetChannelCount()
+        .map_err(|e| format!("Failed to get channel count: {:?}", e))?;
+
+    let mut volumes = Vec::with_capacity(count as usize);
+    for i in 0..count {
+        let level = endpoint
+            .GetChannelVolumeLevelScalar(i)
+            .map_err(|e| format!("Failed to get channel {} volume: {:?}", i, e))?;
+        volumes.push(level);
+    }
+
+    Ok(volumes)
+}
+
+/// L/Rバランスを設定する (-1.0: 左のみ 〜 0.0: 中央 〜 +1.0: 右のみ)
+///
+/// 前方2チャンネル（左・右）の個別スカラー値を、中心からの偏りに応じて
+/// 一方を減衰させることで表現する。
+#[cfg(windows)]
+unsafe fn set_balance(endpoint: &IAudioEndpointVolume, balance: f32) -> Result<(), String> {
+    let balance = balance.clamp(-1.0, 1.0);
+
+    let count = endpoint
+        .GetChannelCount()
+        .map_err(|e| format!("Failed to get channel count: {:?}", e))?;
+    if count < 2 {
+        return Err("Device does not have separate left/right channels".to_string());
+    }
+
+    let left = if balance <= 0.0 { 1.0 } else { 1.0 - balance };
+    let right = if balance >= 0.0 { 1.0 } else { 1.0 + balance };
+
+    endpoint
+        .SetChannelVolumeLevelScalar(0, left, &*EVENT_CONTEXT as *const GUID)
+        .map_err(|e| format!("Failed to set left channel volume: {:?}", e))?;
+    endpoint
+        .SetChannelVolumeLevelScalar(1, right, &*EVENT_CONTEXT as *const GUID)
+        .map_err(|e| format!("Failed to set right channel volume: {:?}", e))?;
+
+    Ok(())
+}
+
+/// `CoTaskMemAlloc` で確保された `PWSTR` をStringへ変換し、確保されたメモリを解放する
+///
+/// `IMMDevice::GetId`、`IAudioSessionControl2::GetDisplayName`/`GetIconPath`、
+/// `PropVariantToStringAlloc` はいずれも呼び出し元が `CoTaskMemFree` で解放する
+/// 必要のある文字列を返すため、変換箇所をここに集約する。
+#[cfg(windows)]
+unsafe fn pwstr_to_string_and_free(pwstr: windows::core::PWSTR) -> String {
+    let value = pwstr.to_string().unwrap_or_default();
+    CoTaskMemFree(Some(pwstr.0 as _));
+    value
+}
+
+/// デバイスの一意なIDを取得する
+#[cfg(windows)]
+unsafe fn device_id_string(device: &IMMDevice) -> String {
+    device
+        .GetId()
+        .ok()
+        .map(pwstr_to_string_and_free)
+        .unwrap_or_default()
+}
+
+/// デバイスのフレンドリ名（表示名）を取得する
+#[cfg(windows)]
+unsafe fn device_friendly_name(device: &IMMDevice) -> Result<String, String> {
+    let store = device
+        .OpenPropertyStore(STGM_READ)
+        .map_err(|e| format!("Failed to open property store: {:?}", e))?;
+    let prop = store
+        .GetValue(&PKEY_Device_FriendlyName)
+        .map_err(|e| format!("Failed to read friendly name: {:?}", e))?;
+    let name = PropVariantToStringAlloc(&prop)
+        .map_err(|e| format!("Failed to convert friendly name: {:?}", e))?;
+
+    Ok(pwstr_to_string_and_free(name))
+}
+
+/// 有効な出力デバイスを列挙する
+#[cfg(windows)]
+unsafe fn list_output_devices(
+    enumerator: &IMMDeviceEnumerator,
+    current_device: &IMMDevice,
+) -> Result<Vec<DeviceInfo>, String> {
+    let current_id = device_id_string(current_device);
+
+    let collection = enumerator
+        .EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)
+        .map_err(|e| format!("Failed to enumerate audio endpoints: {:?}", e))?;
+
+    let count = collection
+        .GetCount()
+        .map_err(|e| format!("Failed to get device count: {:?}", e))?;
+
+    let mut devices = Vec::new();
+    for i in 0..count {
+        let Ok(dev) = collection.Item(i) else {
+            continue;
+        };
+        let id = device_id_string(&dev);
+        let name = device_friendly_name(&dev).unwrap_or_else(|_| id.clone());
+        devices.push(DeviceInfo {
+            is_default: id == current_id,
+            id,
+            name,
+        });
+    }
+
+    Ok(devices)
+}
+
+/// デバイス上の全オーディオセッション（アプリごとの音量）を列挙する
+#[cfg(windows)]
+unsafe fn list_audio_sessions(device: &IMMDevice) -> Result<Vec<SessionInfo>, String> {
+    let session_manager: IAudioSessionManager2 = device
         .Activate(CLSCTX_INPROC_SERVER, None)
-        .map_err(|e| format!("Failed to activate audio endpoint volume: {:?}", e))?;
+        .map_err(|e| format!("Failed to activate session manager: {:?}", e))?;
+
+    let enumerator = session_manager
+        .GetSessionEnumerator()
+        .map_err(|e| format!("Failed to get session enumerator: {:?}", e))?;
+
+    let count = enumerator
+        .GetCount()
+        .map_err(|e| format!("Failed to get session count: {:?}", e))?;
+
+    let mut sessions = Vec::new();
+    for i in 0..count {
+        let Ok(control) = enumerator.GetSession(i) else {
+            continue;
+        };
+        let Ok(control2) = control.cast::<IAudioSessionControl2>() else {
+            continue;
+        };
+
+        let pid = control2.GetProcessId().unwrap_or(0);
+        if pid == 0 {
+            continue;
+        }
+
+        let display_name = control2
+            .GetDisplayName()
+            .ok()
+            .map(pwstr_to_string_and_free)
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| process_name_from_pid(pid));
 
-    Ok(endpoint)
+        let icon_path = control2
+            .GetIconPath()
+            .ok()
+            .map(pwstr_to_string_and_free)
+            .unwrap_or_default();
+
+        let Ok(simple_volume) = control2.cast::<ISimpleAudioVolume>() else {
+            continue;
+        };
+
+        let volume = simple_volume.GetMasterVolume().unwrap_or(0.0);
+        let muted = simple_volume
+            .GetMute()
+            .map(|m| m.as_bool())
+            .unwrap_or(false);
+
+        sessions.push(SessionInfo {
+            pid,
+            display_name,
+            icon_path,
+            volume,
+            muted,
+        });
+    }
+
+    Ok(sessions)
+}
+
+/// 指定したプロセスIDのオーディオセッションの音量を設定する
+#[cfg(windows)]
+unsafe fn set_session_volume(device: &IMMDevice, pid: u32, level: f32) -> Result<f32, String> {
+    let clamped = level.clamp(0.0, 1.0);
+
+    let session_manager: IAudioSessionManager2 = device
+        .Activate(CLSCTX_INPROC_SERVER, None)
+        .map_err(|e| format!("Failed to activate session manager: {:?}", e))?;
+
+    let enumerator = session_manager
+        .GetSessionEnumerator()
+        .map_err(|e| format!("Failed to get session enumerator: {:?}", e))?;
+
+    let count = enumerator
+        .GetCount()
+        .map_err(|e| format!("Failed to get session count: {:?}", e))?;
+
+    for i in 0..count {
+        let Ok(control) = enumerator.GetSession(i) else {
+            continue;
+        };
+        let Ok(control2) = control.cast::<IAudioSessionControl2>() else {
+            continue;
+        };
+        if control2.GetProcessId().unwrap_or(0) != pid {
+            continue;
+        }
+
+        let simple_volume: ISimpleAudioVolume = control2
+            .cast()
+            .map_err(|e| format!("Failed to get session volume control: {:?}", e))?;
+        simple_volume
+            .SetMasterVolume(clamped, &*EVENT_CONTEXT as *const GUID)
+            .map_err(|e| format!("Failed to set session volume: {:?}", e))?;
+
+        return Ok(clamped);
+    }
+
+    Err(format!("No audio session found for pid {}", pid))
+}
+
+/// プロセスIDから実行ファイル名を取得する（セッションの表示名が空の場合のフォールバック）
+#[cfg(windows)]
+unsafe fn process_name_from_pid(pid: u32) -> String {
+    let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+        return format!("pid:{}", pid);
+    };
+
+    let mut buffer = [0u16; 260];
+    let mut size = buffer.len() as u32;
+    let name = if QueryFullProcessImageNameW(
+        handle,
+        PROCESS_NAME_WIN32,
+        windows::core::PWSTR(buffer.as_mut_ptr()),
+        &mut size,
+    )
+    .is_ok()
+    {
+        String::from_utf16_lossy(&buffer[..size as usize])
+    } else {
+        format!("pid:{}", pid)
+    };
+
+    let _ = CloseHandle(handle);
+
+    name.rsplit(['\\', '/']).next().unwrap_or(&name).to_string()
 }
 
 /// 非Windowsプラットフォーム用のスタブ
 #[cfg(not(windows))]
-fn volume_worker_thread(_rx: Receiver<VolumeCommand>) {
+fn volume_worker_thread(
+    _command_tx: Sender<VolumeCommand>,
+    _rx: Receiver<VolumeCommand>,
+    _notify_tx: Sender<f32>,
+    _device_notify_tx: Sender<String>,
+) {
     eprintln!("[VolumeController] Volume control is only supported on Windows");
 }
 
 /// コマンドを送信して結果を待つヘルパー関数
-fn send_command<F>(create_command: F) -> Result<f32, String>
+fn send_command<T, F>(create_command: F) -> Result<T, String>
 where
-    F: FnOnce(Sender<Result<f32, String>>) -> VolumeCommand,
+    F: FnOnce(Sender<Result<T, String>>) -> VolumeCommand,
 {
     let controller = VOLUME_CONTROLLER.lock().unwrap();
     let tx = controller
@@ -182,16 +805,79 @@ pub fn set_volume(level: f32) -> Result<f32, String> {
     send_command(|tx| VolumeCommand::SetVolume(level, tx))
 }
 
-/// ボリュームを5%上げる
+/// ボリュームを設定済みの刻み幅・スケールに従って上げる
 pub fn volume_up() -> Result<f32, String> {
     send_command(VolumeCommand::VolumeUp)
 }
 
-/// ボリュームを5%下げる
+/// ボリュームを設定済みの刻み幅・スケールに従って下げる
 pub fn volume_down() -> Result<f32, String> {
     send_command(VolumeCommand::VolumeDown)
 }
 
+/// `volume_up`/`volume_down` の刻み幅とスケールを設定する
+pub fn set_step_config(step: f32, scale: VolumeScale) -> Result<(), String> {
+    send_command(|tx| VolumeCommand::SetStepConfig(step, scale, tx))
+}
+
+/// エンドポイントのチャンネル数を取得する
+pub fn get_channel_count() -> Result<u32, String> {
+    send_command(VolumeCommand::GetChannelCount)
+}
+
+/// 全チャンネルの現在の音量を取得する
+pub fn get_channel_volumes() -> Result<Vec<f32>, String> {
+    send_command(VolumeCommand::GetChannelVolumes)
+}
+
+/// 指定したチャンネルの音量を設定する (0.0 - 1.0)
+pub fn set_channel_volume(index: u32, level: f32) -> Result<f32, String> {
+    send_command(|tx| VolumeCommand::SetChannelVolume(index, level, tx))
+}
+
+/// L/Rバランスを設定する (-1.0: 左のみ 〜 0.0: 中央 〜 +1.0: 右のみ)
+pub fn set_balance(balance: f32) -> Result<(), String> {
+    send_command(|tx| VolumeCommand::SetBalance(balance, tx))
+}
+
+/// 現在のミュート状態を取得
+pub fn get_mute() -> Result<bool, String> {
+    send_command(VolumeCommand::GetMute)
+}
+
+/// ミュート状態を設定
+pub fn set_mute(muted: bool) -> Result<bool, String> {
+    send_command(|tx| VolumeCommand::SetMute(muted, tx))
+}
+
+/// ミュート状態を反転する
+pub fn toggle_mute() -> Result<bool, String> {
+    send_command(VolumeCommand::ToggleMute)
+}
+
+/// 起動中アプリケーションのオーディオセッション一覧を取得する
+pub fn list_sessions() -> Result<Vec<SessionInfo>, String> {
+    send_command(VolumeCommand::ListSessions)
+}
+
+/// 指定したプロセスIDのアプリケーションの音量を設定する (0.0 - 1.0)
+pub fn set_session_volume(pid: u32, level: f32) -> Result<f32, String> {
+    send_command(|tx| VolumeCommand::SetSessionVolume(pid, level, tx))
+}
+
+/// 有効な出力デバイスの一覧を取得する
+pub fn list_devices() -> Result<Vec<DeviceInfo>, String> {
+    send_command(VolumeCommand::ListDevices)
+}
+
+/// 操作対象の出力デバイスを指定したデバイスIDへ切り替える
+///
+/// Windowsの既定デバイスは変更しない。既定デバイスの変更やデバイスの状態変化を
+/// 検知すると、操作対象は既定デバイスに戻る。
+pub fn set_device(device_id: String) -> Result<(), String> {
+    send_command(|tx| VolumeCommand::SetDevice(device_id, tx))
+}
+
 /// ボリュームコントローラーをシャットダウン
 #[allow(dead_code)]
 pub fn shutdown_volume_controller() {